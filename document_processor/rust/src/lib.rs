@@ -1,7 +1,9 @@
-use calamine::{open_workbook, DataType, Range, Reader, Xlsx};
+use calamine::{open_workbook_auto_from_rs, CellErrorType, DataType, Range, Reader};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDate, PyDateTime, PyDict, PyList, PyTime};
+use regex::Regex;
+use std::io::{Cursor, Read};
 use std::path::PathBuf;
 use thiserror::Error;
 
@@ -9,12 +11,235 @@ use thiserror::Error;
 pub enum ExcelParserError {
     #[error("Failed to open Excel file: {0}")]
     OpenError(#[from] calamine::Error),
-    
+
     #[error("Sheet not found: {0}")]
     SheetNotFound(String),
-    
+
     #[error("Failed to parse Excel content: {0}")]
     ParseError(String),
+
+    #[error("Could not determine workbook format from content: {0}")]
+    UnknownFormat(String),
+}
+
+/// File extensions `open_workbook_auto` knows how to dispatch on.
+const SUPPORTED_FORMATS: &[&str] = &["xlsx", "xlsb", "xls", "ods"];
+
+/// Which epoch a workbook's date serial numbers are counted from.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DateSystem {
+    /// The default Excel epoch, where serial 1 is 1900-01-01 and the
+    /// numbering includes Excel's well-known fictitious 1900-02-29.
+    Excel1900,
+    /// The epoch used by old Mac Excel workbooks, 1462 days later.
+    Excel1904,
+}
+
+impl DateSystem {
+    fn parse(value: &str) -> PyResult<Self> {
+        match value {
+            "1900" => Ok(DateSystem::Excel1900),
+            "1904" => Ok(DateSystem::Excel1904),
+            other => Err(PyValueError::new_err(format!(
+                "Unsupported date_system '{}', expected '1900' or '1904'",
+                other
+            ))),
+        }
+    }
+}
+
+/// Look for the `date1904` workbook property that flags the old Mac Excel
+/// epoch. Only `.xlsx` stores this as the XML attribute we look for, in
+/// `xl/workbook.xml` (`.xlsb`'s equivalent part, `xl/workbook.bin`, is a
+/// binary record we don't parse, so `.xlsb` always falls back to 1900 here);
+/// anything else, or a workbook where the property is absent, uses the 1900
+/// system too.
+fn detect_date_system(content: &[u8]) -> DateSystem {
+    let cursor = Cursor::new(content);
+    let archive = zip::ZipArchive::new(cursor).ok();
+    let mut archive = match archive {
+        Some(a) => a,
+        None => return DateSystem::Excel1900,
+    };
+
+    let mut xml = String::new();
+    if archive
+        .by_name("xl/workbook.xml")
+        .ok()
+        .and_then(|mut entry| entry.read_to_string(&mut xml).ok())
+        .is_none()
+    {
+        return DateSystem::Excel1900;
+    }
+
+    if xml.contains("date1904=\"1\"") || xml.contains("date1904=\"true\"") {
+        DateSystem::Excel1904
+    } else {
+        DateSystem::Excel1900
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic-Gregorian `(year, month, day)`,
+/// via Howard Hinnant's `days_from_civil` algorithm.
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (m + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Inverse of [`days_from_civil`]: a day count since 1970-01-01 to a
+/// proleptic-Gregorian `(year, month, day)`.
+fn civil_from_days(z: i64) -> (i64, i64, i64) {
+    let z = z + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+/// Split an Excel date serial (integer days, with a fractional time-of-day)
+/// into calendar parts under the given date system.
+fn excel_serial_to_parts(serial: f64, date_system: DateSystem) -> (i64, u32, u32, u32, u32, u32) {
+    let days = serial.trunc() as i64;
+
+    // Excel's 1900 system has a fictitious 1900-02-29 at serial 60, which
+    // shifts every later serial one day later relative to the real
+    // calendar. Serials 1-59 (1900-01-01 through 1900-02-28) are unaffected
+    // and need the plain 1899-12-31 epoch; serial 60 onward need the
+    // 1899-12-30 epoch so the extra fictitious day cancels out. Serial 60
+    // itself has no real date: with that epoch it collapses onto 1900-02-28,
+    // the same as serial 59 (xlrd/openpyxl both do the same, treating the
+    // fictitious day as a duplicate of the 28th rather than a distinct date).
+    let epoch_days = match date_system {
+        DateSystem::Excel1900 if days < 60 => days_from_civil(1899, 12, 31),
+        DateSystem::Excel1900 => days_from_civil(1899, 12, 30),
+        DateSystem::Excel1904 => days_from_civil(1904, 1, 1),
+    };
+
+    let (year, month, day) = civil_from_days(epoch_days + days);
+
+    let total_seconds = (serial.fract().abs() * 86400.0).round() as i64;
+    let hour = (total_seconds / 3600) % 24;
+    let minute = (total_seconds / 60) % 60;
+    let second = total_seconds % 60;
+
+    (year, month as u32, day as u32, hour as u32, minute as u32, second as u32)
+}
+
+/// Convert an Excel date serial number into the Python type that best
+/// matches it: a bare time for fractional-only serials, a bare date for
+/// whole-day serials, and a full datetime otherwise.
+fn excel_serial_to_pyobject(
+    py: Python<'_>,
+    serial: f64,
+    date_system: DateSystem,
+) -> PyResult<PyObject> {
+    let (year, month, day, hour, minute, second) = excel_serial_to_parts(serial, date_system);
+
+    if serial.trunc() == 0.0 && serial.fract() != 0.0 {
+        let time = PyTime::new(py, hour as u8, minute as u8, second as u8, 0, None)?;
+        return Ok(time.into());
+    }
+
+    if serial.fract() == 0.0 {
+        let date = PyDate::new(py, year as i32, month as u8, day as u8)?;
+        return Ok(date.into());
+    }
+
+    let datetime = PyDateTime::new(
+        py,
+        year as i32,
+        month as u8,
+        day as u8,
+        hour as u8,
+        minute as u8,
+        second as u8,
+        0,
+        None,
+    )?;
+    Ok(datetime.into())
+}
+
+/// Sniff the workbook container format from its magic bytes, so malformed
+/// content is rejected early and a caller's `format` hint can be checked.
+///
+/// - `PK\x03\x04` is a zip local-file-header: xlsx, xlsb and ods are all
+///   zip containers, so we peek at the inner entries to tell them apart.
+/// - `\xD0\xCF\x11\xE0` is the OLE2 compound-file signature used by legacy
+///   `.xls` workbooks.
+fn detect_format(content: &[u8]) -> Result<&'static str, ExcelParserError> {
+    if content.starts_with(&[0xD0, 0xCF, 0x11, 0xE0]) {
+        return Ok("xls");
+    }
+
+    if content.starts_with(&[0x50, 0x4B, 0x03, 0x04]) {
+        let cursor = std::io::Cursor::new(content);
+        let mut archive = zip::ZipArchive::new(cursor)
+            .map_err(|e| ExcelParserError::UnknownFormat(format!("invalid zip container: {}", e)))?;
+
+        for i in 0..archive.len() {
+            let mut entry = archive
+                .by_index(i)
+                .map_err(|e| ExcelParserError::UnknownFormat(e.to_string()))?;
+            match entry.name() {
+                "xl/workbook.xml" => return Ok("xlsx"),
+                "xl/workbook.bin" => return Ok("xlsb"),
+                "content.xml" => return Ok("ods"),
+                "mimetype" => {
+                    let mut mimetype = String::new();
+                    if entry.read_to_string(&mut mimetype).is_ok()
+                        && mimetype.trim() == "application/vnd.oasis.opendocument.spreadsheet"
+                    {
+                        return Ok("ods");
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        return Err(ExcelParserError::UnknownFormat(
+            "zip container did not contain a recognized workbook entry".to_string(),
+        ));
+    }
+
+    Err(ExcelParserError::UnknownFormat(
+        "content does not start with a known zip or OLE2 header".to_string(),
+    ))
+}
+
+/// Validate a caller-supplied `format` hint against the format actually
+/// sniffed from the content. `open_workbook_auto_from_rs` always
+/// auto-detects the container itself, so the hint can't select a backend —
+/// this only catches a caller's wrong assumption about the file it passed.
+fn check_format_hint(hint: Option<&str>, detected_format: &str) -> Result<(), String> {
+    let Some(hint) = hint else {
+        return Ok(());
+    };
+
+    if !SUPPORTED_FORMATS.contains(&hint) {
+        return Err(format!(
+            "Unsupported format hint '{}', expected one of {:?}",
+            hint, SUPPORTED_FORMATS
+        ));
+    }
+
+    if hint != detected_format {
+        return Err(format!(
+            "Format hint '{}' does not match the detected container format '{}'",
+            hint, detected_format
+        ));
+    }
+
+    Ok(())
 }
 
 #[pyclass]
@@ -27,27 +252,65 @@ impl RustExcelParser {
         RustExcelParser {}
     }
 
-    /// Parse an Excel file from bytes and return a dictionary of sheets
-    #[pyo3(text_signature = "(content, /)")]
-    fn parse_excel<'py>(&self, py: Python<'py>, content: &[u8]) -> PyResult<&'py PyDict> {
+    /// Parse an Excel file from bytes and return a dictionary of sheets.
+    ///
+    /// `format` may be given as an explicit hint (`"xlsx"`, `"xlsb"`, `"xls"`
+    /// or `"ods"`) when the caller already knows the container type; it is
+    /// checked against the format sniffed from `content`'s magic bytes and
+    /// rejected if the two disagree. The container is always opened via
+    /// auto-detection (`open_workbook_auto_from_rs` has no way to be told
+    /// which backend to use), so `format` can't select a backend — it can
+    /// only catch a caller's wrong assumption about the file it's passing
+    /// in. Omit it to skip the check and rely on sniffing alone.
+    ///
+    /// `skip_rows` physical rows (title banners, blank rows) are skipped
+    /// before the header is resolved. `header_row`, if given, is an absolute
+    /// row index to use as the header instead of `skip_rows`. If neither is
+    /// given and `auto_header` is set, the first row at or after `skip_rows`
+    /// with more than one non-empty string cell is used as the header. The
+    /// last `skip_footer` data rows (totals, signature lines) are dropped.
+    ///
+    /// `DateTime` cells are converted to `datetime`/`date`/`time` objects.
+    /// `date_system` overrides the epoch used for that conversion (`"1900"`
+    /// or `"1904"`) instead of detecting it from the workbook; `raw_dates`
+    /// skips the conversion and returns the bare Excel serial number.
+    #[pyo3(signature = (content, format=None, header_row=None, skip_rows=0, skip_footer=0, auto_header=false, date_system=None, raw_dates=false))]
+    #[pyo3(text_signature = "(content, format=None, header_row=None, skip_rows=0, skip_footer=0, auto_header=False, date_system=None, raw_dates=False, /)")]
+    #[allow(clippy::too_many_arguments)]
+    fn parse_excel<'py>(
+        &self,
+        py: Python<'py>,
+        content: &[u8],
+        format: Option<&str>,
+        header_row: Option<usize>,
+        skip_rows: usize,
+        skip_footer: usize,
+        auto_header: bool,
+        date_system: Option<&str>,
+        raw_dates: bool,
+    ) -> PyResult<&'py PyDict> {
         let result = PyDict::new(py);
-        
-        // Create a temporary file to write the content
-        let temp_dir = tempfile::tempdir().map_err(|e| {
-            PyValueError::new_err(format!("Failed to create temporary directory: {}", e))
-        })?;
-        
-        let temp_path = temp_dir.path().join("temp.xlsx");
-        std::fs::write(&temp_path, content).map_err(|e| {
-            PyValueError::new_err(format!("Failed to write temporary file: {}", e))
-        })?;
-        
-        // Open the Excel file
-        let mut workbook: Xlsx<_> = match open_workbook(&temp_path) {
+
+        // Validate up front so a malformed container fails with a clear
+        // error rather than a confusing one from inside calamine. This also
+        // doubles as the `format` hint check below, since there's no way to
+        // tell `open_workbook_auto_from_rs` which backend to use directly.
+        let detected_format = detect_format(content).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        check_format_hint(format, detected_format).map_err(PyValueError::new_err)?;
+
+        let date_system = match date_system {
+            Some(hint) => DateSystem::parse(hint)?,
+            None => detect_date_system(content),
+        };
+
+        // Read straight out of the in-memory buffer; calamine's readers only
+        // need `Read + Seek`, which a `Cursor` over the borrowed bytes gives
+        // us for free, so there's no need to round-trip through a temp file.
+        let mut workbook = match open_workbook_auto_from_rs(Cursor::new(content)) {
             Ok(wb) => wb,
             Err(e) => return Err(PyValueError::new_err(format!("Failed to open Excel file: {}", e))),
         };
-        
+
         // Process each sheet
         for sheet_name in workbook.sheet_names().to_owned() {
             let sheet = match workbook.worksheet_range(&sheet_name) {
@@ -58,45 +321,116 @@ impl RustExcelParser {
                     )))
                 }
             };
-            
-            let sheet_data = self.process_sheet(py, &sheet)?;
+
+            let sheet_data = self.process_sheet(
+                py,
+                &sheet,
+                header_row,
+                skip_rows,
+                skip_footer,
+                auto_header,
+                date_system,
+                raw_dates,
+            )?;
             result.set_item(sheet_name, sheet_data)?;
         }
-        
+
         Ok(result)
     }
     
-    /// Extract questions from sheets
-    #[pyo3(text_signature = "(sheets, /)")]
-    fn extract_questions<'py>(&self, py: Python<'py>, sheets: &PyDict) -> PyResult<&'py PyList> {
+    /// Detect questions in parsed sheets with a pluggable matcher.
+    ///
+    /// Built in: cells ending with `?`, the full-width `？` or Arabic `؟`
+    /// question marks, numbered/lettered enumerations (`1. What is...`,
+    /// `Q1)`, `a) Please describe...`), and a `"question"` keyword fallback.
+    /// `patterns` adds caller-supplied regexes on top of those. Each match
+    /// is returned with the row's other non-empty cells as `context`
+    /// (candidate answer options / metadata) and a guessed `type` of
+    /// `"enumerated"`, `"yes-no"` (every context cell is one of
+    /// `yes_no_values`) or `"open"`.
+    ///
+    /// A bare enumeration prefix only counts when it's followed by real
+    /// prompt text (see [`has_enumeration_prefix`]) — this keeps short
+    /// answer-option cells sitting in the same row as a real question
+    /// (`"A) Yes"`, `"B) No"`) out of the results as their own questions;
+    /// they still show up in that question's `context`.
+    #[pyo3(signature = (sheets, patterns=None, yes_no_values=None))]
+    #[pyo3(text_signature = "(sheets, patterns=None, yes_no_values=None, /)")]
+    fn extract_questions<'py>(
+        &self,
+        py: Python<'py>,
+        sheets: &PyDict,
+        patterns: Option<Vec<String>>,
+        yes_no_values: Option<Vec<String>>,
+    ) -> PyResult<&'py PyList> {
         let questions = PyList::empty(py);
-        
+
+        let user_patterns = patterns
+            .unwrap_or_default()
+            .iter()
+            .map(|p| {
+                Regex::new(p)
+                    .map_err(|e| PyValueError::new_err(format!("Invalid pattern '{}': {}", p, e)))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let yes_no_values = yes_no_values.unwrap_or_else(default_yes_no_values);
+
         for (sheet_name, sheet_data) in sheets.iter() {
             let sheet_data = sheet_data.downcast::<PyList>()?;
-            
+
             for (row_idx, row) in sheet_data.iter().enumerate() {
                 let row = row.downcast::<PyDict>()?;
-                
-                // Look for cells that appear to be questions
-                for (col_key, cell_value) in row.iter() {
-                    let cell_str = cell_value.to_string();
-                    
-                    // Simple heuristic: cells ending with ? are likely questions
-                    // In a real implementation, we'd use more sophisticated detection
-                    if cell_str.trim().ends_with("?") || 
-                       cell_str.to_lowercase().contains("question") {
-                        let question_dict = PyDict::new(py);
-                        question_dict.set_item("sheet", sheet_name)?;
-                        question_dict.set_item("row", row_idx)?;
-                        question_dict.set_item("column", col_key)?;
-                        question_dict.set_item("text", cell_str)?;
-                        
-                        questions.append(question_dict)?;
+
+                let cells: Vec<(String, String)> = row
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect();
+
+                for (col_key, cell_str) in &cells {
+                    let is_enumerated = has_enumeration_prefix(cell_str);
+
+                    let is_question = is_enumerated
+                        || ends_with_question_mark(cell_str)
+                        || cell_str.to_lowercase().contains("question")
+                        || user_patterns.iter().any(|re| re.is_match(cell_str));
+
+                    if !is_question {
+                        continue;
                     }
+
+                    let context: Vec<&String> = cells
+                        .iter()
+                        .filter(|(k, v)| k != col_key && !v.trim().is_empty())
+                        .map(|(_, v)| v)
+                        .collect();
+
+                    let question_type = if is_enumerated {
+                        "enumerated"
+                    } else if is_yes_no_context(&context, &yes_no_values) {
+                        "yes-no"
+                    } else {
+                        "open"
+                    };
+
+                    let question_dict = PyDict::new(py);
+                    question_dict.set_item("sheet", sheet_name)?;
+                    question_dict.set_item("row", row_idx)?;
+                    question_dict.set_item("column", col_key)?;
+                    question_dict.set_item("text", cell_str)?;
+                    question_dict.set_item("type", question_type)?;
+
+                    let context_list = PyList::empty(py);
+                    for value in &context {
+                        context_list.append(value.as_str())?;
+                    }
+                    question_dict.set_item("context", context_list)?;
+
+                    questions.append(question_dict)?;
                 }
             }
         }
-        
+
         Ok(questions)
     }
     
@@ -132,65 +466,746 @@ impl RustExcelParser {
         Ok(structure)
     }
 
+    /// Parse like `parse_excel`, but also return a per-sheet data-quality
+    /// report so validation pipelines can reject or flag corrupt
+    /// questionnaires before extraction. Returns `(sheets, report)`, where
+    /// `report` is keyed by sheet name with `error_cells` (a list of
+    /// `{row, column, error_type}`), `empty_count` and `row_count`.
+    #[pyo3(text_signature = "(content, /)")]
+    fn parse_excel_with_report<'py>(
+        &self,
+        py: Python<'py>,
+        content: &[u8],
+    ) -> PyResult<(&'py PyDict, &'py PyDict)> {
+        detect_format(content).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let date_system = detect_date_system(content);
+
+        let result = PyDict::new(py);
+        let report = PyDict::new(py);
+
+        let mut workbook = match open_workbook_auto_from_rs(Cursor::new(content)) {
+            Ok(wb) => wb,
+            Err(e) => return Err(PyValueError::new_err(format!("Failed to open Excel file: {}", e))),
+        };
+
+        for sheet_name in workbook.sheet_names().to_owned() {
+            let sheet = match workbook.worksheet_range(&sheet_name) {
+                Ok(range) => range,
+                Err(e) => {
+                    return Err(PyValueError::new_err(format!(
+                        "Error reading sheet {}: {}", sheet_name, e
+                    )))
+                }
+            };
+
+            let sheet_data = self.process_sheet(py, &sheet, None, 0, 0, false, date_system, false)?;
+            let sheet_report = compute_sheet_report(&sheet).into_pydict(py)?;
+
+            result.set_item(&sheet_name, sheet_data)?;
+            report.set_item(&sheet_name, sheet_report)?;
+        }
+
+        Ok((result, report))
+    }
+
+    /// Open a single sheet lazily: rows are converted to `PyDict`s one at a
+    /// time as Python iterates, instead of materializing the whole sheet
+    /// up front. See [`RustSheetRows`].
+    ///
+    /// `header_row`, `skip_rows`, `skip_footer` and `auto_header` mean the
+    /// same as in [`Self::parse_excel`] and are resolved the same way, so
+    /// streaming and eager parsing agree on which row is the header and
+    /// which trailing rows are dropped.
+    #[pyo3(signature = (content, sheet_name, header_row=None, skip_rows=0, skip_footer=0, auto_header=false, date_system=None, raw_dates=false))]
+    #[pyo3(text_signature = "(content, sheet_name, header_row=None, skip_rows=0, skip_footer=0, auto_header=False, date_system=None, raw_dates=False, /)")]
+    #[allow(clippy::too_many_arguments)]
+    fn parse_excel_iter(
+        &self,
+        content: &[u8],
+        sheet_name: &str,
+        header_row: Option<usize>,
+        skip_rows: usize,
+        skip_footer: usize,
+        auto_header: bool,
+        date_system: Option<&str>,
+        raw_dates: bool,
+    ) -> PyResult<RustSheetRows> {
+        let date_system = match date_system {
+            Some(hint) => DateSystem::parse(hint)?,
+            None => detect_date_system(content),
+        };
+
+        let mut workbook = match open_workbook_auto_from_rs(Cursor::new(content)) {
+            Ok(wb) => wb,
+            Err(e) => return Err(PyValueError::new_err(format!("Failed to open Excel file: {}", e))),
+        };
+
+        let range = workbook.worksheet_range(sheet_name).map_err(|e| {
+            PyValueError::new_err(format!("Error reading sheet {}: {}", sheet_name, e))
+        })?;
+
+        let header_idx = resolve_header_index(&range, skip_rows, header_row, auto_header);
+
+        Ok(RustSheetRows::new(
+            range,
+            header_idx,
+            skip_footer,
+            date_system,
+            raw_dates,
+        ))
+    }
+
     // Helper method to process a sheet into a list of dictionaries
-    fn process_sheet<'py>(&self, py: Python<'py>, range: &Range<DataType>) -> PyResult<&'py PyList> {
+    #[allow(clippy::too_many_arguments)]
+    fn process_sheet<'py>(
+        &self,
+        py: Python<'py>,
+        range: &Range<DataType>,
+        header_row: Option<usize>,
+        skip_rows: usize,
+        skip_footer: usize,
+        auto_header: bool,
+        date_system: DateSystem,
+        raw_dates: bool,
+    ) -> PyResult<&'py PyList> {
         let rows = PyList::empty(py);
-        
-        // Get headers from the first row
+
         if range.height() == 0 {
             return Ok(rows);
         }
-        
-        let headers: Vec<String> = range.rows()
-            .next()
-            .unwrap()
-            .iter()
-            .map(|cell| match cell {
-                DataType::String(s) => s.clone(),
-                DataType::Int(i) => i.to_string(),
-                DataType::Float(f) => f.to_string(),
-                DataType::Bool(b) => b.to_string(),
-                _ => "".to_string(),
-            })
+
+        let header_idx = match resolve_header_index(range, skip_rows, header_row, auto_header) {
+            Some(idx) if idx < range.height() => idx,
+            _ => return Ok(rows),
+        };
+
+        let headers = stringify_row(range, header_idx);
+
+        // Process data rows, dropping the footer trailer if requested
+        let (first_data_row, end_row) = data_row_bounds(range.height(), header_idx, skip_footer);
+        let data_rows: Vec<_> = range
+            .rows()
+            .skip(first_data_row)
+            .take(end_row - first_data_row)
             .collect();
-        
-        // Process data rows
-        for row_data in range.rows().skip(1) {
+
+        for row_data in data_rows {
             let row_dict = PyDict::new(py);
-            
+
             for (i, cell) in row_data.iter().enumerate() {
                 if i >= headers.len() {
                     continue;
                 }
-                
-                let header = &headers[i];
-                if header.is_empty() {
-                    continue;
-                }
-                
-                // Convert cell to appropriate Python type
-                let value = match cell {
-                    DataType::String(s) => s.clone().to_object(py),
-                    DataType::Int(i) => i.to_object(py),
-                    DataType::Float(f) => f.to_object(py),
-                    DataType::Bool(b) => b.to_object(py),
-                    DataType::DateTime(d) => d.to_object(py),
-                    DataType::Empty => py.None(),
-                    DataType::Error(_) => py.None(),
-                };
-                
-                row_dict.set_item(header, value)?;
+
+                let value = cell_to_object(py, cell, date_system, raw_dates)?;
+                row_dict.set_item(&headers[i], value)?;
             }
-            
+
             rows.append(row_dict)?;
         }
-        
+
         Ok(rows)
     }
 }
 
+/// Find the row to use as the header.
+///
+/// An explicit `header_row` always wins. Otherwise, when `auto_header` is
+/// set, scan downward from `skip_rows` for the first row with more than one
+/// non-empty string cell (CrossMgr's heuristic for a real header row among
+/// title banners and blank rows), returning `None` if no row qualifies.
+/// Without `auto_header`, `skip_rows` itself is the header row.
+fn resolve_header_index(
+    range: &Range<DataType>,
+    skip_rows: usize,
+    header_row: Option<usize>,
+    auto_header: bool,
+) -> Option<usize> {
+    const AUTO_HEADER_MIN_NON_EMPTY: usize = 1;
+
+    if header_row.is_some() {
+        return header_row;
+    }
+
+    if auto_header {
+        return (skip_rows..range.height()).find(|&idx| {
+            let non_empty = range
+                .rows()
+                .nth(idx)
+                .map(|row| {
+                    row.iter()
+                        .filter(|cell| matches!(cell, DataType::String(s) if !s.trim().is_empty()))
+                        .count()
+                })
+                .unwrap_or(0);
+            non_empty > AUTO_HEADER_MIN_NON_EMPTY
+        });
+    }
+
+    Some(skip_rows)
+}
+
+/// The data-row range that follows a header: the first data row index and
+/// the exclusive end index once `skip_footer` trailing rows are dropped.
+/// Shared by the eager parser (`process_sheet`) and the streaming iterator
+/// (`RustSheetRows::new`) so they agree on which rows count as data.
+fn data_row_bounds(height: usize, header_idx: usize, skip_footer: usize) -> (usize, usize) {
+    let first_data_row = header_idx + 1;
+    let data_row_count = height
+        .saturating_sub(first_data_row)
+        .saturating_sub(skip_footer);
+    (first_data_row, first_data_row + data_row_count)
+}
+
+/// Read row `row_idx` of `range` as header names, stringifying int, float
+/// and bool cells. Blank or whitespace-only string cells, and empty, date
+/// or error cells, get a stable `<col_NNN>` placeholder instead of an
+/// empty string so blank header cells never collide or silently drop
+/// columns.
+fn stringify_row(range: &Range<DataType>, row_idx: usize) -> Vec<String> {
+    range
+        .rows()
+        .nth(row_idx)
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(col_idx, cell)| match cell {
+                    DataType::String(s) if !s.trim().is_empty() => s.clone(),
+                    DataType::Int(i) => i.to_string(),
+                    DataType::Float(f) => f.to_string(),
+                    DataType::Bool(b) => b.to_string(),
+                    _ => format!("<col_{:03}>", col_idx),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Convert a single calamine cell into the Python type it should surface as.
+/// `DateTime` cells become real `datetime`/`date`/`time` objects unless
+/// `raw_dates` asks for the bare Excel serial number instead.
+fn cell_to_object(
+    py: Python<'_>,
+    cell: &DataType,
+    date_system: DateSystem,
+    raw_dates: bool,
+) -> PyResult<PyObject> {
+    Ok(match cell {
+        DataType::String(s) => s.clone().to_object(py),
+        DataType::Int(i) => i.to_object(py),
+        DataType::Float(f) => f.to_object(py),
+        DataType::Bool(b) => b.to_object(py),
+        DataType::DateTime(d) if raw_dates => d.to_object(py),
+        DataType::DateTime(d) => excel_serial_to_pyobject(py, *d, date_system)?,
+        DataType::Empty => py.None(),
+        DataType::Error(_) => py.None(),
+    })
+}
+
+/// Question marks recognized beyond the plain ASCII `?`: full-width (common
+/// in CJK spreadsheets) and Arabic.
+const QUESTION_MARK_CHARS: &[char] = &['?', '\u{FF1F}', '\u{061F}'];
+
+fn ends_with_question_mark(text: &str) -> bool {
+    text.trim()
+        .chars()
+        .last()
+        .is_some_and(|c| QUESTION_MARK_CHARS.contains(&c))
+}
+
+/// Regex for the unambiguous `Q1)`, `q2:` style prefix — the `Q`/`q` marker
+/// makes this a reliable signal on its own, unlike the bare digit/letter
+/// prefixes below which need the trailing-content checks in
+/// [`has_enumeration_prefix`].
+const QUESTION_NUMBER_PATTERN: &str = r"(?i)^\s*q\s*\d+\s*[.):]";
+
+/// Which kind of bare prefix [`strip_numeric_or_letter_prefix`] stripped —
+/// the two kinds need different minimum trailing content in
+/// [`has_enumeration_prefix`], since a single letter followed by a period is
+/// also how abbreviations like `"I."` (roman numeral / initial) continue,
+/// while a leading digit has no such competing reading.
+enum PrefixKind {
+    Digit,
+    Letter,
+}
+
+/// True when `text` opens with a numbered/lettered enumeration prefix
+/// (`1.`, `1)`, `a)`, `A.`, `Q1)`, `q2:`) followed by an actual prompt,
+/// rather than just the prefix alone.
+///
+/// A bare `^[A-Za-z]\s*[.)]` match is too weak on its own: it fires on
+/// abbreviations like `"N.B. check this"`, `"e.g. see appendix"` and
+/// `"I. Introduction"` just as readily as on a real enumerated question like
+/// `"a) Please describe your role"`. Letter prefixes need at least two
+/// trailing words to rule those out. Digit prefixes (`"1)"`, `"2)"`) don't
+/// have that competing abbreviation reading, so a single trailing word is
+/// enough — this is what makes single-word numbered prompts like
+/// `"1) Email"` or `"2) Name"` count. Either way, a first trailing word that
+/// is itself a short `XX.`-style token (the hallmark of a multi-part
+/// abbreviation continuing past the prefix, as in `"N.B."` or `"e.g."`) is
+/// rejected.
+fn has_enumeration_prefix(text: &str) -> bool {
+    static QUESTION_NUMBER_RE: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
+
+    let trimmed = text.trim();
+
+    let question_number_re = QUESTION_NUMBER_RE.get_or_init(|| {
+        Regex::new(QUESTION_NUMBER_PATTERN).expect("built-in enumeration pattern is valid")
+    });
+    if question_number_re.is_match(trimmed) {
+        return true;
+    }
+
+    let (kind, rest) = match strip_numeric_or_letter_prefix(trimmed) {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    let mut words = rest.split_whitespace();
+    let first_word = match words.next() {
+        Some(w) => w,
+        None => return false,
+    };
+    if matches!(kind, PrefixKind::Letter) && words.next().is_none() {
+        return false;
+    }
+
+    let is_abbreviation_continuation = first_word.len() <= 3
+        && first_word.ends_with('.')
+        && first_word[..first_word.len() - 1]
+            .chars()
+            .all(|c| c.is_alphabetic());
+
+    !is_abbreviation_continuation
+}
+
+/// Strip a leading `\d+[.)]` or single-letter `[A-Za-z][.)]` prefix and
+/// return its kind plus the remainder, or `None` if `text` doesn't open with
+/// one.
+///
+/// The punctuation must be followed by whitespace or the end of the string —
+/// without that, `"3.14 total cost"` would otherwise parse as enumeration
+/// prefix `"3."` followed by `"14 total cost"` and get misread as a question.
+fn strip_numeric_or_letter_prefix(text: &str) -> Option<(PrefixKind, &str)> {
+    let text = text.trim_start();
+    let mut chars = text.char_indices();
+    let (_, first) = chars.next()?;
+
+    let (kind, prefix_end) = if first.is_ascii_digit() {
+        let end = text
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len());
+        (PrefixKind::Digit, end)
+    } else if first.is_ascii_alphabetic() {
+        (PrefixKind::Letter, chars.next()?.0)
+    } else {
+        return None;
+    };
+
+    let rest = &text[prefix_end..];
+    let after_punct = rest.strip_prefix('.').or_else(|| rest.strip_prefix(')'))?;
+    if !after_punct.is_empty() && !after_punct.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    Some((kind, after_punct.trim_start()))
+}
+
+fn default_yes_no_values() -> Vec<String> {
+    ["yes", "no", "y", "n", "true", "false"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// True when `context` is non-empty and every cell in it normalizes to one
+/// of `yes_no_values`, i.e. the row looks like a yes/no question with its
+/// answer options sitting alongside it.
+fn is_yes_no_context(context: &[&String], yes_no_values: &[String]) -> bool {
+    if context.is_empty() {
+        return false;
+    }
+    context.iter().all(|value| {
+        let normalized = value.trim().to_lowercase();
+        yes_no_values
+            .iter()
+            .any(|candidate| candidate.to_lowercase() == normalized)
+    })
+}
+
+/// Per-sheet data-quality diagnostics, modeled on calamine's `search_errors`
+/// example: every error cell's coordinates and kind, plus how many cells in
+/// the sheet are empty and how many rows it has in total.
+struct SheetReport {
+    error_cells: Vec<(usize, usize, &'static str)>,
+    empty_count: usize,
+    row_count: usize,
+}
+
+impl SheetReport {
+    fn into_pydict<'py>(self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+
+        let error_cells = PyList::empty(py);
+        for (row, column, error_type) in self.error_cells {
+            let cell = PyDict::new(py);
+            cell.set_item("row", row)?;
+            cell.set_item("column", column)?;
+            cell.set_item("error_type", error_type)?;
+            error_cells.append(cell)?;
+        }
+
+        dict.set_item("error_cells", error_cells)?;
+        dict.set_item("empty_count", self.empty_count)?;
+        dict.set_item("row_count", self.row_count)?;
+        Ok(dict)
+    }
+}
+
+/// The Excel-visible label for a calamine cell error, e.g. `#REF!`/`#DIV/0!`.
+fn excel_error_label(error: &CellErrorType) -> &'static str {
+    match error {
+        CellErrorType::Div0 => "#DIV/0!",
+        CellErrorType::NA => "#N/A",
+        CellErrorType::Name => "#NAME?",
+        CellErrorType::Null => "#NULL!",
+        CellErrorType::Num => "#NUM!",
+        CellErrorType::Ref => "#REF!",
+        CellErrorType::Value => "#VALUE!",
+        CellErrorType::GettingData => "#GETTING_DATA",
+    }
+}
+
+/// Scan every cell of `range`, independent of any header/skip configuration,
+/// to build its [`SheetReport`].
+fn compute_sheet_report(range: &Range<DataType>) -> SheetReport {
+    let mut error_cells = Vec::new();
+    let mut empty_count = 0;
+
+    for (row_idx, row) in range.rows().enumerate() {
+        for (col_idx, cell) in row.iter().enumerate() {
+            match cell {
+                DataType::Error(e) => error_cells.push((row_idx, col_idx, excel_error_label(e))),
+                DataType::Empty => empty_count += 1,
+                _ => {}
+            }
+        }
+    }
+
+    SheetReport {
+        error_cells,
+        empty_count,
+        row_count: range.height(),
+    }
+}
+
+/// Lazy, per-row iterator over a single sheet, mirroring python-calamine's
+/// `iter_rows`. Holds the already-opened `Range` and yields one
+/// header-keyed `PyDict` per call to `__next__` instead of building the
+/// whole sheet's rows up front. The header row is resolved by
+/// `parse_excel_iter` with the same `resolve_header_index` that
+/// `parse_excel` uses; [`Self::new`] then reads the header names and the
+/// data-row bounds (`data_row_bounds`, which drops `skip_footer` rows) once
+/// up front.
+#[pyclass]
+struct RustSheetRows {
+    range: Range<DataType>,
+    headers: Vec<String>,
+    next_row: u32,
+    end_row: u32,
+    date_system: DateSystem,
+    raw_dates: bool,
+}
+
+impl RustSheetRows {
+    /// `header_idx` is the already-resolved header row (see
+    /// `resolve_header_index`); `None`, or an index past the end of the
+    /// sheet, yields an iterator with no rows, matching `process_sheet`'s
+    /// behavior for the eager parser.
+    fn new(
+        range: Range<DataType>,
+        header_idx: Option<usize>,
+        skip_footer: usize,
+        date_system: DateSystem,
+        raw_dates: bool,
+    ) -> Self {
+        let height = range.height();
+
+        let header_idx = match header_idx {
+            Some(idx) if idx < height => idx,
+            _ => {
+                return RustSheetRows {
+                    range,
+                    headers: Vec::new(),
+                    next_row: 0,
+                    end_row: 0,
+                    date_system,
+                    raw_dates,
+                }
+            }
+        };
+
+        let headers = stringify_row(&range, header_idx);
+        let (first_data_row, end_row) = data_row_bounds(height, header_idx, skip_footer);
+
+        RustSheetRows {
+            range,
+            headers,
+            next_row: first_data_row as u32,
+            end_row: end_row as u32,
+            date_system,
+            raw_dates,
+        }
+    }
+}
+
+#[pymethods]
+impl RustSheetRows {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python<'_>) -> PyResult<Option<Py<PyDict>>> {
+        let row_idx = slf.next_row;
+        if row_idx >= slf.end_row {
+            return Ok(None);
+        }
+        slf.next_row += 1;
+
+        let headers = slf.headers.clone();
+        let date_system = slf.date_system;
+        let raw_dates = slf.raw_dates;
+        let row_dict = PyDict::new(py);
+        for (col_idx, header) in headers.iter().enumerate() {
+            if header.is_empty() {
+                continue;
+            }
+            let cell = slf
+                .range
+                .rows()
+                .nth(row_idx as usize)
+                .and_then(|row| row.get(col_idx));
+            if let Some(cell) = cell {
+                let value = cell_to_object(py, cell, date_system, raw_dates)?;
+                row_dict.set_item(header, value)?;
+            }
+        }
+
+        Ok(Some(row_dict.into()))
+    }
+}
+
 #[pymodule]
 fn excel_parser(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<RustExcelParser>()?;
+    m.add_class::<RustSheetRows>()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn excel_1900_serial_before_fake_leap_day() {
+        // Serial 1 is 1900-01-01, serial 59 is 1900-02-28: both before the
+        // fictitious 1900-02-29 at serial 60, so the plain epoch applies.
+        assert_eq!(
+            excel_serial_to_parts(1.0, DateSystem::Excel1900),
+            (1900, 1, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            excel_serial_to_parts(59.0, DateSystem::Excel1900),
+            (1900, 2, 28, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn excel_1900_serial_on_fake_leap_day() {
+        // Serial 60 is the fictitious 1900-02-29 itself: it has no real
+        // date, and collapses onto 1900-02-28, the same as serial 59.
+        assert_eq!(
+            excel_serial_to_parts(60.0, DateSystem::Excel1900),
+            (1900, 2, 28, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn excel_1900_serial_after_fake_leap_day() {
+        // Serial 61 is 1900-03-01, the first real date past the fictitious
+        // 1900-02-29.
+        assert_eq!(
+            excel_serial_to_parts(61.0, DateSystem::Excel1900),
+            (1900, 3, 1, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn excel_1900_serial_modern_date() {
+        // 45292 is 2024-01-01 under the 1900 system.
+        assert_eq!(
+            excel_serial_to_parts(45292.0, DateSystem::Excel1900),
+            (2024, 1, 1, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn excel_1900_serial_with_time_of_day() {
+        // 45292.5 is 2024-01-01 noon.
+        assert_eq!(
+            excel_serial_to_parts(45292.5, DateSystem::Excel1900),
+            (2024, 1, 1, 12, 0, 0)
+        );
+    }
+
+    #[test]
+    fn enumeration_prefix_accepts_real_prompts() {
+        assert!(has_enumeration_prefix("1. What is your name"));
+        assert!(has_enumeration_prefix("a) Please describe your role"));
+        assert!(has_enumeration_prefix("Q1) How satisfied are you"));
+        assert!(has_enumeration_prefix("q2: Any other comments"));
+    }
+
+    #[test]
+    fn enumeration_prefix_rejects_abbreviations() {
+        assert!(!has_enumeration_prefix("I. Introduction"));
+        assert!(!has_enumeration_prefix("e.g. see appendix"));
+        assert!(!has_enumeration_prefix("N.B. check this"));
+    }
+
+    #[test]
+    fn enumeration_prefix_rejects_bare_answer_options() {
+        assert!(!has_enumeration_prefix("A) Yes"));
+        assert!(!has_enumeration_prefix("B) No"));
+    }
+
+    #[test]
+    fn enumeration_prefix_rejects_decimal_numbers() {
+        assert!(!has_enumeration_prefix("3.14 total cost"));
+        assert!(!has_enumeration_prefix("19.99 USD shipped"));
+    }
+
+    #[test]
+    fn enumeration_prefix_accepts_single_word_numbered_prompts() {
+        assert!(has_enumeration_prefix("1) Email"));
+        assert!(has_enumeration_prefix("2) Name"));
+        assert!(has_enumeration_prefix("3) Age"));
+    }
+
+    /// Build an in-memory zip archive with a single named entry, for
+    /// exercising `detect_format`'s container-sniffing without fixture
+    /// files on disk.
+    fn zip_with_entry(name: &str, contents: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        {
+            let mut writer = zip::ZipWriter::new(std::io::Cursor::new(&mut buf));
+            writer
+                .start_file(name, zip::write::FileOptions::default())
+                .expect("start_file");
+            writer.write_all(contents).expect("write entry contents");
+            writer.finish().expect("finish zip");
+        }
+        buf
+    }
+
+    #[test]
+    fn detect_format_recognizes_xlsx() {
+        let content = zip_with_entry("xl/workbook.xml", b"<workbook/>");
+        assert_eq!(detect_format(&content).unwrap(), "xlsx");
+    }
+
+    #[test]
+    fn detect_format_recognizes_xlsb() {
+        let content = zip_with_entry("xl/workbook.bin", b"binary-workbook-part");
+        assert_eq!(detect_format(&content).unwrap(), "xlsb");
+    }
+
+    #[test]
+    fn detect_format_recognizes_ods_by_content_entry() {
+        let content = zip_with_entry("content.xml", b"<office:document-content/>");
+        assert_eq!(detect_format(&content).unwrap(), "ods");
+    }
+
+    #[test]
+    fn detect_format_recognizes_ods_by_mimetype_entry() {
+        let content = zip_with_entry(
+            "mimetype",
+            b"application/vnd.oasis.opendocument.spreadsheet",
+        );
+        assert_eq!(detect_format(&content).unwrap(), "ods");
+    }
+
+    #[test]
+    fn detect_format_recognizes_xls_ole2_header() {
+        let content = [0xD0, 0xCF, 0x11, 0xE0, 0x00, 0x00];
+        assert_eq!(detect_format(&content).unwrap(), "xls");
+    }
+
+    #[test]
+    fn detect_format_rejects_zip_without_recognized_entry() {
+        let content = zip_with_entry("unrelated.txt", b"not a workbook");
+        assert!(detect_format(&content).is_err());
+    }
+
+    #[test]
+    fn detect_format_rejects_unknown_header() {
+        let content = b"not a workbook at all";
+        assert!(detect_format(content).is_err());
+    }
+
+    #[test]
+    fn format_hint_absent_always_passes() {
+        assert!(check_format_hint(None, "xlsx").is_ok());
+    }
+
+    #[test]
+    fn format_hint_matching_detected_format_passes() {
+        assert!(check_format_hint(Some("ods"), "ods").is_ok());
+    }
+
+    #[test]
+    fn format_hint_rejects_unsupported_value() {
+        assert!(check_format_hint(Some("pdf"), "pdf").is_err());
+    }
+
+    #[test]
+    fn format_hint_rejects_mismatch_with_detected_format() {
+        // Caller claims "xls" but the content sniffed as "ods".
+        assert!(check_format_hint(Some("xls"), "ods").is_err());
+    }
+
+    #[test]
+    fn data_row_bounds_without_footer() {
+        // Header at row 0, 10 total rows: 9 data rows, none dropped.
+        assert_eq!(data_row_bounds(10, 0, 0), (1, 10));
+    }
+
+    #[test]
+    fn data_row_bounds_drops_footer_rows() {
+        // Same sheet, but the last 2 rows are a footer to drop.
+        assert_eq!(data_row_bounds(10, 0, 2), (1, 8));
+    }
+
+    #[test]
+    fn data_row_bounds_skip_footer_past_data_saturates_to_empty() {
+        // A footer larger than the data itself should yield an empty
+        // range, not underflow.
+        assert_eq!(data_row_bounds(3, 0, 10), (1, 1));
+    }
+
+    #[test]
+    fn excel_error_label_covers_every_variant() {
+        assert_eq!(excel_error_label(&CellErrorType::Div0), "#DIV/0!");
+        assert_eq!(excel_error_label(&CellErrorType::NA), "#N/A");
+        assert_eq!(excel_error_label(&CellErrorType::Name), "#NAME?");
+        assert_eq!(excel_error_label(&CellErrorType::Null), "#NULL!");
+        assert_eq!(excel_error_label(&CellErrorType::Num), "#NUM!");
+        assert_eq!(excel_error_label(&CellErrorType::Ref), "#REF!");
+        assert_eq!(excel_error_label(&CellErrorType::Value), "#VALUE!");
+        assert_eq!(
+            excel_error_label(&CellErrorType::GettingData),
+            "#GETTING_DATA"
+        );
+    }
+}